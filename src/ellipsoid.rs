@@ -0,0 +1,443 @@
+//! Ellipsoidal system of reference for geodetic computations.
+
+use crate::{cartesian, float::Float, geographic};
+
+/// Describes an [oblate spheroid](https://en.wikipedia.org/wiki/Earth_ellipsoid) used as a
+/// reference surface for geodetic computations.
+///
+/// ## Definition
+/// An ellipsoid of revolution is fully determined by its semi-major axis (the equatorial
+/// radius) and its flattening, here expressed as the inverse flattening since that is how most
+/// geodetic datums publish it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipsoid {
+    /// The equatorial radius of the ellipsoid, in meters.
+    pub semi_major_axis: Float,
+    /// The reciprocal of the ellipsoid's flattening.
+    pub inverse_flattening: Float,
+}
+
+impl Ellipsoid {
+    /// The [World Geodetic System 1984](https://en.wikipedia.org/wiki/World_Geodetic_System)
+    /// reference ellipsoid, as used by GPS.
+    pub const WGS84: Self = Self {
+        semi_major_axis: 6_378_137.0,
+        inverse_flattening: 298.257223563,
+    };
+
+    /// Returns the ellipsoid's flattening `f = 1 / inverse_flattening`.
+    pub fn flattening(&self) -> Float {
+        1. / self.inverse_flattening
+    }
+
+    /// Returns the ellipsoid's semi-minor axis `b = a·(1 − f)`.
+    pub fn semi_minor_axis(&self) -> Float {
+        self.semi_major_axis * (1. - self.flattening())
+    }
+
+    /// Returns the ellipsoid's (first) eccentricity squared `e² = f·(2 − f)`.
+    pub fn eccentricity_sq(&self) -> Float {
+        let f = self.flattening();
+        f * (2. - f)
+    }
+
+    /// Converts the given [geographic::Coordinates], relative to `self`, into
+    /// [Earth-Centered, Earth-Fixed](https://en.wikipedia.org/wiki/Earth-centered,_Earth-fixed_coordinate_system)
+    /// [cartesian::Coordinates].
+    pub fn to_ecef(&self, coordinates: &geographic::Coordinates) -> cartesian::Coordinates {
+        let latitude = coordinates.latitude.as_float();
+        let longitude = coordinates.longitude.as_float();
+        let altitude = coordinates.altitude.as_float();
+
+        let prime_vertical_radius = self.prime_vertical_radius(latitude);
+
+        cartesian::Coordinates {
+            x: (prime_vertical_radius + altitude) * latitude.cos() * longitude.cos(),
+            y: (prime_vertical_radius + altitude) * latitude.cos() * longitude.sin(),
+            z: (prime_vertical_radius * (1. - self.eccentricity_sq()) + altitude) * latitude.sin(),
+        }
+    }
+
+    /// Converts the given [Earth-Centered, Earth-Fixed](https://en.wikipedia.org/wiki/Earth-centered,_Earth-fixed_coordinate_system)
+    /// [cartesian::Coordinates] into [geographic::Coordinates] relative to `self`, using
+    /// [Bowring's iterative method](https://en.wikipedia.org/wiki/Geographic_coordinate_conversion#Bowring's_formula).
+    ///
+    /// Since [geographic::Altitude] only holds non-negative values, altitudes below the
+    /// ellipsoid surface are returned as their absolute distance to it.
+    pub fn from_ecef(&self, coordinates: &cartesian::Coordinates) -> geographic::Coordinates {
+        const ITERATIONS: usize = 3;
+
+        let cartesian::Coordinates { x, y, z } = *coordinates;
+
+        let a = self.semi_major_axis;
+        let b = self.semi_minor_axis();
+        let e_sq = self.eccentricity_sq();
+        let e_prime_sq = e_sq * a.powi(2) / b.powi(2);
+        let f = self.flattening();
+
+        let p = (x.powi(2) + y.powi(2)).sqrt();
+        let mut beta = (z * a).atan2(p * b);
+        let mut latitude = 0.;
+
+        for _ in 0..ITERATIONS {
+            latitude = (z + e_prime_sq * b * beta.sin().powi(3))
+                .atan2(p - e_sq * a * beta.cos().powi(3));
+            beta = ((1. - f) * latitude.sin()).atan2(latitude.cos());
+        }
+
+        let longitude = y.atan2(x);
+        let altitude = p / latitude.cos() - self.prime_vertical_radius(latitude);
+
+        geographic::Coordinates::default()
+            .with_longitude(longitude.into())
+            .with_latitude(latitude.into())
+            .with_altitude(altitude.into())
+    }
+
+    /// Returns the [prime vertical radius of curvature](https://en.wikipedia.org/wiki/Earth_radius#Prime_vertical)
+    /// `N = a / sqrt(1 − e²·sin²φ)` at the given latitude, in radians.
+    fn prime_vertical_radius(&self, latitude: Float) -> Float {
+        self.semi_major_axis / (1. - self.eccentricity_sq() * latitude.sin().powi(2)).sqrt()
+    }
+
+    /// Solves the inverse geodesic problem between `from` and `to` using
+    /// [Vincenty's formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae#Inverse_problem),
+    /// returning the distance between both points along the surface of `self`, in meters.
+    ///
+    /// Near-antipodal points may make the iteration fail to converge, in which case the
+    /// [great-circle](geographic::Coordinates::distance) distance is returned instead.
+    pub fn inverse(
+        &self,
+        from: &geographic::Coordinates,
+        to: &geographic::Coordinates,
+    ) -> GeodesicResult {
+        const MAX_ITERATIONS: usize = 200;
+        const TOLERANCE: Float = 1e-12;
+
+        let a = self.semi_major_axis;
+        let b = self.semi_minor_axis();
+        let f = self.flattening();
+
+        let u1 = ((1. - f) * from.latitude.as_float().tan()).atan();
+        let u2 = ((1. - f) * to.latitude.as_float().tan()).atan();
+        let l = to.longitude.as_float() - from.longitude.as_float();
+
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut sin_sigma;
+        let mut cos_sigma;
+        let mut sigma;
+        let mut cos_sq_alpha;
+        let mut cos_2sigma_m;
+        let mut converged = false;
+        let mut iterations = 0;
+
+        loop {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+            sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+
+            if sin_sigma == 0. {
+                // `from` and `to` are the same point.
+                return GeodesicResult {
+                    distance: 0.,
+                    converged: true,
+                };
+            }
+
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1. - sin_alpha.powi(2);
+
+            cos_2sigma_m = if cos_sq_alpha == 0. {
+                // the geodesic runs along the equator.
+                0.
+            } else {
+                cos_sigma - 2. * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+
+            let c = f / 16. * cos_sq_alpha * (4. + f * (4. - 3. * cos_sq_alpha));
+            let prev_lambda = lambda;
+
+            lambda = l
+                + (1. - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+
+            iterations += 1;
+
+            if (lambda - prev_lambda).abs() < TOLERANCE {
+                converged = true;
+                break;
+            }
+
+            if iterations >= MAX_ITERATIONS {
+                break;
+            }
+        }
+
+        if !converged {
+            return GeodesicResult {
+                distance: from.distance(to) * a,
+                converged: false,
+            };
+        }
+
+        let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+        let cap_a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+        let cap_b = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+
+        let delta_sigma = cap_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + cap_b / 4.
+                    * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                        - cap_b / 6.
+                            * cos_2sigma_m
+                            * (-3. + 4. * sin_sigma.powi(2))
+                            * (-3. + 4. * cos_2sigma_m.powi(2))));
+
+        GeodesicResult {
+            distance: b * cap_a * (sigma - delta_sigma),
+            converged: true,
+        }
+    }
+
+    /// Solves the [direct geodesic problem](https://en.wikipedia.org/wiki/Vincenty%27s_formulae#Direct_problem)
+    /// on the surface of `self`: given a starting point, a bearing (in radians, where north is
+    /// `0` and east is `+π/2`) and a distance (in meters), returns the arrival point.
+    pub fn direct(
+        &self,
+        from: &geographic::Coordinates,
+        bearing: Float,
+        distance: Float,
+    ) -> geographic::Coordinates {
+        const MAX_ITERATIONS: usize = 200;
+        const TOLERANCE: Float = 1e-12;
+
+        let a = self.semi_major_axis;
+        let b = self.semi_minor_axis();
+        let f = self.flattening();
+
+        let u1 = ((1. - f) * from.latitude.as_float().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_alpha1, cos_alpha1) = bearing.sin_cos();
+
+        let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos_sq_alpha = 1. - sin_alpha.powi(2);
+
+        let u_sq = cos_sq_alpha * (a.powi(2) - b.powi(2)) / b.powi(2);
+        let cap_a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+        let cap_b = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+
+        let mut sigma = distance / (b * cap_a);
+        let mut cos_2sigma_m = 0.;
+        let mut sin_sigma = 0.;
+        let mut cos_sigma = 0.;
+
+        for _ in 0..MAX_ITERATIONS {
+            cos_2sigma_m = (2. * sigma1 + sigma).cos();
+            sin_sigma = sigma.sin();
+            cos_sigma = sigma.cos();
+
+            let delta_sigma = cap_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + cap_b / 4.
+                        * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                            - cap_b / 6.
+                                * cos_2sigma_m
+                                * (-3. + 4. * sin_sigma.powi(2))
+                                * (-3. + 4. * cos_2sigma_m.powi(2))));
+
+            let prev_sigma = sigma;
+            sigma = distance / (b * cap_a) + delta_sigma;
+
+            if (sigma - prev_sigma).abs() < TOLERANCE {
+                break;
+            }
+        }
+
+        let latitude = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1).atan2(
+            (1. - f)
+                * (sin_alpha.powi(2) + (sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1).powi(2))
+                    .sqrt(),
+        );
+
+        let lambda = (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = f / 16. * cos_sq_alpha * (4. + f * (4. - 3. * cos_sq_alpha));
+        let l = lambda
+            - (1. - c)
+                * f
+                * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+
+        geographic::Coordinates::default()
+            .with_longitude((from.longitude.as_float() + l).into())
+            .with_latitude(latitude.into())
+            .with_altitude(from.altitude)
+    }
+}
+
+/// The outcome of solving the ellipsoidal inverse geodesic problem via [Ellipsoid::inverse].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeodesicResult {
+    /// The geodesic distance between the two points, in meters.
+    pub distance: Float,
+    /// Whether Vincenty's iteration converged within [tolerance](https://en.wikipedia.org/wiki/Vincenty%27s_formulae#Nearly_antipodal_points),
+    /// as opposed to falling back to the great-circle approximation.
+    pub converged: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cartesian, ellipsoid::Ellipsoid, geographic, tests::approx_eq};
+
+    #[test]
+    fn to_ecef_and_back_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: geographic::Coordinates,
+        }
+
+        vec![
+            Test {
+                name: "equator on the prime meridian",
+                input: geographic::Coordinates::default(),
+            },
+            Test {
+                name: "north pole",
+                input: geographic::Coordinates::default()
+                    .with_latitude(geographic::Latitude::from(std::f64::consts::FRAC_PI_2)),
+            },
+            Test {
+                name: "arbitrary point with altitude",
+                input: geographic::Coordinates::default()
+                    .with_latitude(geographic::Latitude::from(0.5))
+                    .with_longitude(geographic::Longitude::from(1.2))
+                    .with_altitude(geographic::Altitude::from(250.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let ecef = Ellipsoid::WGS84.to_ecef(&test.input);
+            let roundtrip = Ellipsoid::WGS84.from_ecef(&ecef);
+
+            assert!(
+                approx_eq(
+                    roundtrip.latitude.as_float(),
+                    test.input.latitude.as_float(),
+                    1e-8
+                ),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                roundtrip.latitude.as_float(),
+                test.input.latitude.as_float()
+            );
+
+            assert!(
+                approx_eq(
+                    roundtrip.longitude.as_float(),
+                    test.input.longitude.as_float(),
+                    1e-8
+                ),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                roundtrip.longitude.as_float(),
+                test.input.longitude.as_float()
+            );
+
+            assert!(
+                approx_eq(
+                    roundtrip.altitude.as_float(),
+                    test.input.altitude.as_float(),
+                    1e-6
+                ),
+                "{}: got altitude = {}, want {}",
+                test.name,
+                roundtrip.altitude.as_float(),
+                test.input.altitude.as_float()
+            );
+        });
+    }
+
+    #[test]
+    fn to_ecef_on_equator_must_match_semi_major_axis() {
+        let ecef = Ellipsoid::WGS84.to_ecef(&geographic::Coordinates::default());
+
+        assert!(approx_eq(ecef.x, Ellipsoid::WGS84.semi_major_axis, 1e-6));
+        assert_eq!(ecef, cartesian::Coordinates::default().with_x(ecef.x));
+    }
+
+    #[test]
+    fn inverse_of_same_point_must_be_zero() {
+        let point = geographic::Coordinates::default()
+            .with_latitude(geographic::Latitude::from(0.3))
+            .with_longitude(geographic::Longitude::from(-1.1));
+
+        let result = Ellipsoid::WGS84.inverse(&point, &point);
+
+        assert_eq!(result.distance, 0.);
+        assert!(result.converged);
+    }
+
+    #[test]
+    fn inverse_must_match_known_distance() {
+        // Wellington, NZ to Salamanca, Spain, per the worked example in Vincenty (1975).
+        let from = geographic::Coordinates::default()
+            .with_latitude(geographic::Latitude::from(-(41. + 19. / 60.) * std::f64::consts::PI / 180.))
+            .with_longitude(geographic::Longitude::from(
+                (174. + 49. / 60.) * std::f64::consts::PI / 180.,
+            ));
+
+        let to = geographic::Coordinates::default()
+            .with_latitude(geographic::Latitude::from(
+                (40. + 58. / 60.) * std::f64::consts::PI / 180.,
+            ))
+            .with_longitude(geographic::Longitude::from(
+                -(5. + 30. / 60.) * std::f64::consts::PI / 180.,
+            ));
+
+        let result = Ellipsoid::WGS84.inverse(&from, &to);
+
+        assert!(result.converged);
+        assert!(
+            approx_eq(result.distance, 19_960_000., 5_000.),
+            "got distance = {}, want ~19960000",
+            result.distance
+        );
+    }
+
+    #[test]
+    fn direct_and_inverse_must_agree() {
+        let from = geographic::Coordinates::default()
+            .with_latitude(geographic::Latitude::from(0.3))
+            .with_longitude(geographic::Longitude::from(-1.1));
+
+        let bearing = 1.2;
+        let distance = 1_500_000.;
+
+        let to = Ellipsoid::WGS84.direct(&from, bearing, distance);
+        let result = Ellipsoid::WGS84.inverse(&from, &to);
+
+        assert!(result.converged);
+        assert!(
+            approx_eq(result.distance, distance, 1e-3),
+            "got distance = {}, want {}",
+            result.distance,
+            distance
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! [RFC 5870](https://www.rfc-editor.org/rfc/rfc5870) `geo:` URI support for [geographic::Coordinates].
+//!
+//! Available under the `geo-uri` feature.
+
+use std::{fmt, str::FromStr};
+
+use crate::geographic;
+
+const SCHEME: &str = "geo:";
+
+/// An error occurred while parsing a `geo:` URI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The URI is missing its latitude and/or longitude.
+    MissingCoordinates,
+    /// One of the coordinate components could not be parsed as a floating point number.
+    InvalidFloat(String),
+    /// The `crs=` parameter names a coordinate reference system other than `wgs84`.
+    UnsupportedCrs(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCoordinates => write!(f, "missing latitude and/or longitude"),
+            Self::InvalidFloat(value) => write!(f, "invalid coordinate value: {value}"),
+            Self::UnsupportedCrs(crs) => {
+                write!(f, "unsupported coordinate reference system: {crs}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl geographic::Coordinates {
+    /// Formats `self` as an [RFC 5870](https://www.rfc-editor.org/rfc/rfc5870) `geo:` URI, e.g.
+    /// `geo:37.786971,-122.399677,250`.
+    pub fn to_geo_uri(&self) -> String {
+        let latitude = self.latitude.to_degrees();
+        let longitude = self.longitude.to_degrees();
+        let altitude = self.altitude.as_float();
+
+        if altitude == 0. {
+            format!("{SCHEME}{latitude},{longitude}")
+        } else {
+            format!("{SCHEME}{latitude},{longitude},{altitude}")
+        }
+    }
+}
+
+impl FromStr for geographic::Coordinates {
+    type Err = Error;
+
+    /// Parses an [RFC 5870](https://www.rfc-editor.org/rfc/rfc5870) `geo:` URI into
+    /// [geographic::Coordinates], converting its decimal degrees into the radians the crate
+    /// stores internally through [geographic::Longitude::from] and [geographic::Latitude::from].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let body = s.strip_prefix(SCHEME).unwrap_or(s);
+        let mut segments = body.split(';');
+
+        let coordinates = segments.next().ok_or(Error::MissingCoordinates)?;
+        let mut values = coordinates.split(',');
+
+        let parse_degrees = |value: &str| -> Result<f64, Error> {
+            value
+                .trim()
+                .parse()
+                .map_err(|_| Error::InvalidFloat(value.to_string()))
+        };
+
+        let latitude = values
+            .next()
+            .ok_or(Error::MissingCoordinates)
+            .and_then(parse_degrees)?;
+
+        let longitude = values
+            .next()
+            .ok_or(Error::MissingCoordinates)
+            .and_then(parse_degrees)?;
+
+        let altitude = values.next().map(parse_degrees).transpose()?.unwrap_or(0.);
+
+        for param in segments {
+            if let Some(crs) = param.strip_prefix("crs=") {
+                if !crs.eq_ignore_ascii_case("wgs84") {
+                    return Err(Error::UnsupportedCrs(crs.to_string()));
+                }
+            }
+
+            // the `u=` uncertainty parameter carries no geometric information, so it is
+            // accepted but otherwise ignored.
+        }
+
+        Ok(Self::from_degrees(latitude, longitude, altitude))
+    }
+}
+
+impl TryFrom<&str> for geographic::Coordinates {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geographic::{Altitude, Coordinates, Latitude, Longitude},
+        geo_uri::Error,
+        tests::approx_eq,
+    };
+
+    #[test]
+    fn from_geo_uri_must_not_fail() {
+        struct Test<'a> {
+            name: &'a str,
+            input: &'a str,
+            want: Coordinates,
+        }
+
+        vec![
+            Test {
+                name: "latitude and longitude only",
+                input: "geo:37.786971,-122.399677",
+                want: Coordinates::default()
+                    .with_latitude(Latitude::from(37.786971_f64.to_radians()))
+                    .with_longitude(Longitude::from((-122.399677_f64).to_radians())),
+            },
+            Test {
+                name: "with altitude",
+                input: "geo:37.786971,-122.399677,250",
+                want: Coordinates::default()
+                    .with_latitude(Latitude::from(37.786971_f64.to_radians()))
+                    .with_longitude(Longitude::from((-122.399677_f64).to_radians()))
+                    .with_altitude(Altitude::from(250.)),
+            },
+            Test {
+                name: "with uncertainty and crs parameters",
+                input: "geo:37.786971,-122.399677,250;crs=wgs84;u=35",
+                want: Coordinates::default()
+                    .with_latitude(Latitude::from(37.786971_f64.to_radians()))
+                    .with_longitude(Longitude::from((-122.399677_f64).to_radians()))
+                    .with_altitude(Altitude::from(250.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got: Coordinates = test.input.parse().expect(test.name);
+
+            assert!(
+                approx_eq(got.latitude.as_float(), test.want.latitude.as_float(), 1e-9),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                got.latitude.as_float(),
+                test.want.latitude.as_float()
+            );
+
+            assert!(
+                approx_eq(
+                    got.longitude.as_float(),
+                    test.want.longitude.as_float(),
+                    1e-9
+                ),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                got.longitude.as_float(),
+                test.want.longitude.as_float()
+            );
+
+            assert_eq!(got.altitude, test.want.altitude, "{}: altitude", test.name);
+        });
+    }
+
+    #[test]
+    fn from_geo_uri_must_fail() {
+        struct Test<'a> {
+            name: &'a str,
+            input: &'a str,
+            want: Error,
+        }
+
+        vec![
+            Test {
+                name: "missing longitude",
+                input: "geo:37.786971",
+                want: Error::MissingCoordinates,
+            },
+            Test {
+                name: "unparsable latitude",
+                input: "geo:not-a-number,-122.399677",
+                want: Error::InvalidFloat("not-a-number".to_string()),
+            },
+            Test {
+                name: "unsupported crs",
+                input: "geo:37.786971,-122.399677;crs=nad83",
+                want: Error::UnsupportedCrs("nad83".to_string()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let got: Result<Coordinates, Error> = test.input.parse();
+
+            assert_eq!(got, Err(test.want), "{}", test.name);
+        });
+    }
+
+    #[test]
+    fn to_geo_uri_must_roundtrip_through_from_str() {
+        let coordinates = Coordinates::default()
+            .with_latitude(Latitude::from(37.786971_f64.to_radians()))
+            .with_longitude(Longitude::from((-122.399677_f64).to_radians()))
+            .with_altitude(Altitude::from(250.));
+
+        let uri = coordinates.to_geo_uri();
+        assert!(uri.starts_with("geo:"), "got uri = {uri}");
+
+        let roundtrip: Coordinates = uri.parse().expect("uri must parse back");
+
+        assert!(approx_eq(
+            roundtrip.latitude.as_float(),
+            coordinates.latitude.as_float(),
+            1e-9
+        ));
+        assert!(approx_eq(
+            roundtrip.longitude.as_float(),
+            coordinates.longitude.as_float(),
+            1e-9
+        ));
+        assert_eq!(roundtrip.altitude, coordinates.altitude);
+    }
+}
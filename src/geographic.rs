@@ -67,6 +67,17 @@ impl Longitude {
     pub fn as_float(&self) -> Float {
         self.0
     }
+
+    /// Builds a [Longitude] from a value in degrees, normalizing it through the same
+    /// range-wrapping logic as converting from radians.
+    pub fn from_degrees(degrees: Float) -> Self {
+        degrees.to_radians().into()
+    }
+
+    /// Returns the value in degrees.
+    pub fn to_degrees(&self) -> Float {
+        self.0.to_degrees()
+    }
 }
 
 /// Represents the vertical axis in a geographic system of coordinates.
@@ -129,6 +140,17 @@ impl Latitude {
     pub fn as_float(&self) -> Float {
         self.0
     }
+
+    /// Builds a [Latitude] from a value in degrees, normalizing it through the same
+    /// range-wrapping logic as converting from radians.
+    pub fn from_degrees(degrees: Float) -> Self {
+        degrees.to_radians().into()
+    }
+
+    /// Returns the value in degrees.
+    pub fn to_degrees(&self) -> Float {
+        self.0.to_degrees()
+    }
 }
 
 /// Represents the radius in a geographic system of coordinates.
@@ -191,6 +213,15 @@ impl From<cartesian::Coordinates> for Coordinates {
 }
 
 impl Coordinates {
+    /// Builds [Coordinates] from a latitude, longitude and altitude given in degrees, wrapping
+    /// out-of-range values through [Latitude::from_degrees] and [Longitude::from_degrees].
+    pub fn from_degrees(latitude: Float, longitude: Float, altitude: Float) -> Self {
+        Self::default()
+            .with_latitude(Latitude::from_degrees(latitude))
+            .with_longitude(Longitude::from_degrees(longitude))
+            .with_altitude(Altitude::from(altitude))
+    }
+
     pub fn with_longitude(self, longitude: Longitude) -> Self {
         Self { longitude, ..self }
     }
@@ -211,13 +242,57 @@ impl Coordinates {
 
         (prod_latitude_sin + prod_latitude_cos * longitude_diff.cos()).acos()
     }
+
+    /// Solves the [direct geodesic problem](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid#Direct_and_inverse_geodesic_problems)
+    /// on the sphere: given a bearing (in radians, where north is `0` and east is `+π/2`) and an
+    /// angular distance (in radians, as returned by [distance](Self::distance)), returns the
+    /// arrival point.
+    pub fn destination(&self, bearing: Float, distance: Float) -> Self {
+        let latitude = self.latitude.as_float();
+        let longitude = self.longitude.as_float();
+
+        let destination_latitude =
+            (latitude.sin() * distance.cos() + latitude.cos() * distance.sin() * bearing.cos())
+                .asin();
+
+        let destination_longitude = longitude
+            + (bearing.sin() * distance.sin() * latitude.cos())
+                .atan2(distance.cos() - latitude.sin() * destination_latitude.sin());
+
+        Self::default()
+            .with_longitude(destination_longitude.into())
+            .with_latitude(destination_latitude.into())
+            .with_altitude(self.altitude)
+    }
+
+    /// Computes the [initial bearing](https://en.wikipedia.org/wiki/Great-circle_navigation#Course_and_distance)
+    /// (forward azimuth) from self towards the given point, in radians, where north is `0` and
+    /// east is `+π/2`.
+    pub fn initial_bearing(&self, rhs: &Self) -> Float {
+        let longitude_diff = rhs.longitude.as_float() - self.longitude.as_float();
+
+        let y = longitude_diff.sin() * rhs.latitude.as_float().cos();
+        let x = self.latitude.as_float().cos() * rhs.latitude.as_float().sin()
+            - self.latitude.as_float().sin() * rhs.latitude.as_float().cos() * longitude_diff.cos();
+
+        y.atan2(x).rem_euclid(TAU)
+    }
+
+    /// Computes the final bearing (forward azimuth on arrival) from self towards the given
+    /// point, in radians, where north is `0` and east is `+π/2`.
+    ///
+    /// This is the [initial_bearing](Self::initial_bearing) of the reverse journey, turned
+    /// around by `π`.
+    pub fn final_bearing(&self, rhs: &Self) -> Float {
+        (rhs.initial_bearing(self) + PI).rem_euclid(TAU)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         cartesian,
-        float::{Float, FRAC_PI_2, PI},
+        float::{Float, FRAC_PI_2, PI, TAU},
         geographic::{Altitude, Coordinates, Latitude, Longitude},
         tests::approx_eq,
     };
@@ -433,4 +508,160 @@ mod tests {
             )
         });
     }
+
+    #[test]
+    fn destination_must_not_fail() {
+        const ABS_ERROR: Float = 0.0000000000000003;
+
+        struct Test<'a> {
+            name: &'a str,
+            from: Coordinates,
+            bearing: Float,
+            distance: Float,
+            want: Coordinates,
+        }
+
+        vec![
+            Test {
+                name: "zero distance must not move",
+                from: Coordinates::default(),
+                bearing: 0.,
+                distance: 0.,
+                want: Coordinates::default(),
+            },
+            Test {
+                name: "heading east along the equator",
+                from: Coordinates::default(),
+                bearing: FRAC_PI_2,
+                distance: 1.,
+                want: Coordinates::default().with_longitude(Longitude::from(1.)),
+            },
+            Test {
+                name: "heading north towards the pole",
+                from: Coordinates::default(),
+                bearing: 0.,
+                distance: FRAC_PI_2,
+                want: Coordinates::default().with_latitude(Latitude::from(FRAC_PI_2)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let destination = test.from.destination(test.bearing, test.distance);
+
+            assert!(
+                approx_eq(
+                    destination.latitude.as_float(),
+                    test.want.latitude.as_float(),
+                    ABS_ERROR
+                ),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                destination.latitude.as_float(),
+                test.want.latitude.as_float()
+            );
+
+            assert!(
+                approx_eq(
+                    destination.longitude.as_float(),
+                    test.want.longitude.as_float(),
+                    ABS_ERROR
+                ),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                destination.longitude.as_float(),
+                test.want.longitude.as_float()
+            );
+        });
+    }
+
+    #[test]
+    fn bearing_must_not_fail() {
+        const ABS_ERROR: Float = 0.0000000000000003;
+
+        struct Test<'a> {
+            name: &'a str,
+            from: Coordinates,
+            to: Coordinates,
+            initial_bearing: Float,
+        }
+
+        vec![
+            Test {
+                name: "heading east along the equator",
+                from: Coordinates::default(),
+                to: Coordinates::default().with_longitude(Longitude::from(1.)),
+                initial_bearing: FRAC_PI_2,
+            },
+            Test {
+                name: "heading north towards the pole",
+                from: Coordinates::default(),
+                to: Coordinates::default().with_latitude(Latitude::from(FRAC_PI_2)),
+                initial_bearing: 0.,
+            },
+            Test {
+                name: "heading west along the equator",
+                from: Coordinates::default(),
+                to: Coordinates::default().with_longitude(Longitude::from(-1.)),
+                initial_bearing: 3. * FRAC_PI_2,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let initial_bearing = test.from.initial_bearing(&test.to);
+
+            assert!(
+                approx_eq(initial_bearing, test.initial_bearing, ABS_ERROR),
+                "{}: got initial bearing = {}, want {}",
+                test.name,
+                initial_bearing,
+                test.initial_bearing
+            );
+        });
+    }
+
+    #[test]
+    fn final_bearing_must_be_initial_bearing_of_reverse_plus_pi() {
+        let from = Coordinates::default();
+        let to = Coordinates::default()
+            .with_latitude(Latitude::from(0.4))
+            .with_longitude(Longitude::from(0.9));
+
+        let final_bearing = from.final_bearing(&to);
+        let want = (to.initial_bearing(&from) + PI).rem_euclid(TAU);
+
+        assert_eq!(final_bearing, want);
+    }
+
+    #[test]
+    fn degrees_must_roundtrip_through_radians() {
+        const ABS_ERROR: Float = 0.0000000000000003;
+
+        assert!(approx_eq(
+            Longitude::from_degrees(90.).as_float(),
+            FRAC_PI_2,
+            ABS_ERROR
+        ));
+        assert_eq!(Longitude::from_degrees(90.).to_degrees(), 90.);
+
+        assert!(approx_eq(
+            Latitude::from_degrees(-90.).as_float(),
+            -FRAC_PI_2,
+            ABS_ERROR
+        ));
+        assert_eq!(Latitude::from_degrees(-90.).to_degrees(), -90.);
+
+        let coordinates = Coordinates::from_degrees(45., 90., 120.);
+
+        assert!(approx_eq(
+            coordinates.latitude.as_float(),
+            Latitude::from_degrees(45.).as_float(),
+            ABS_ERROR
+        ));
+        assert!(approx_eq(
+            coordinates.longitude.as_float(),
+            Longitude::from_degrees(90.).as_float(),
+            ABS_ERROR
+        ));
+        assert_eq!(coordinates.altitude, Altitude::from(120.));
+    }
 }
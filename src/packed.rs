@@ -0,0 +1,183 @@
+//! Compact, [Eq] + [Hash] encoding of geographic coordinates.
+
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    float::{Float, FRAC_PI_2, PI},
+    geographic,
+};
+
+/// Sentinel value reserved to mark a [PackedCoordinates] as invalid / unset.
+const INVALID: i32 = i32::MIN;
+
+/// A memory-dense, [Eq] and [Hash] encoding of [geographic::Coordinates], storing longitude and
+/// latitude as scaled fixed-point [i32] values.
+///
+/// ## Definition
+/// Longitude spans `[-π, +π)` and latitude spans `[-π/2, +π/2]`; both are linearly mapped onto
+/// the `[i32::MIN + 1, i32::MAX]` range so that equality and hashing become exact integer
+/// operations instead of lossy floating point comparisons. `i32::MIN` is reserved to represent an
+/// invalid / unset coordinate, distinguishing [Default] from the valid origin.
+///
+/// The mapping is lossless within the quantization step of the target range, which is
+/// significantly finer than the precision most applications storing coordinates at this density
+/// require. Altitude is not encoded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedCoordinates {
+    longitude: i32,
+    latitude: i32,
+}
+
+impl Default for PackedCoordinates {
+    fn default() -> Self {
+        Self {
+            longitude: INVALID,
+            latitude: INVALID,
+        }
+    }
+}
+
+impl Eq for PackedCoordinates {}
+
+impl Hash for PackedCoordinates {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.longitude.hash(state);
+        self.latitude.hash(state);
+    }
+}
+
+impl From<geographic::Coordinates> for PackedCoordinates {
+    fn from(coordinates: geographic::Coordinates) -> Self {
+        Self {
+            longitude: pack(coordinates.longitude.as_float(), -PI, PI),
+            latitude: pack(coordinates.latitude.as_float(), -FRAC_PI_2, FRAC_PI_2),
+        }
+    }
+}
+
+impl From<PackedCoordinates> for geographic::Coordinates {
+    fn from(packed: PackedCoordinates) -> Self {
+        Self::default()
+            .with_longitude(unpack(packed.longitude, -PI, PI).into())
+            .with_latitude(unpack(packed.latitude, -FRAC_PI_2, FRAC_PI_2).into())
+    }
+}
+
+impl PackedCoordinates {
+    /// Returns whether `self` holds an actual coordinate, as opposed to the sentinel produced by
+    /// [Default::default].
+    pub fn is_valid(&self) -> bool {
+        self.longitude != INVALID
+    }
+}
+
+/// Linearly maps `value`, within `[min, max]`, onto `[i32::MIN + 1, i32::MAX]`, reserving
+/// `i32::MIN` for [INVALID].
+fn pack(value: Float, min: Float, max: Float) -> i32 {
+    const RANGE: Float = i32::MAX as Float - (i32::MIN as Float + 1.);
+
+    let ratio = (value - min) / (max - min);
+    (i32::MIN as Float + 1. + ratio * RANGE).round() as i32
+}
+
+/// The inverse of [pack].
+fn unpack(value: i32, min: Float, max: Float) -> Float {
+    const RANGE: Float = i32::MAX as Float - (i32::MIN as Float + 1.);
+
+    let ratio = (value as Float - (i32::MIN as Float + 1.)) / RANGE;
+    min + ratio * (max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        float::{FRAC_PI_2, PI},
+        geographic::{self, Latitude, Longitude},
+        packed::PackedCoordinates,
+        tests::approx_eq,
+    };
+
+    #[test]
+    fn default_must_be_invalid() {
+        assert!(!PackedCoordinates::default().is_valid());
+    }
+
+    #[test]
+    fn packed_coordinates_must_roundtrip() {
+        struct Test<'a> {
+            name: &'a str,
+            input: geographic::Coordinates,
+        }
+
+        vec![
+            Test {
+                name: "origin",
+                input: geographic::Coordinates::default(),
+            },
+            Test {
+                name: "north pole",
+                input: geographic::Coordinates::default()
+                    .with_latitude(Latitude::from(FRAC_PI_2)),
+            },
+            Test {
+                name: "west boundary",
+                input: geographic::Coordinates::default().with_longitude(Longitude::from(-PI)),
+            },
+            Test {
+                name: "arbitrary point",
+                input: geographic::Coordinates::default()
+                    .with_latitude(Latitude::from(0.4))
+                    .with_longitude(Longitude::from(-2.1)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let packed = PackedCoordinates::from(test.input);
+            assert!(packed.is_valid(), "{}: must be valid", test.name);
+
+            let roundtrip = geographic::Coordinates::from(packed);
+
+            assert!(
+                approx_eq(
+                    roundtrip.latitude.as_float(),
+                    test.input.latitude.as_float(),
+                    1e-6
+                ),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                roundtrip.latitude.as_float(),
+                test.input.latitude.as_float()
+            );
+
+            assert!(
+                approx_eq(
+                    roundtrip.longitude.as_float(),
+                    test.input.longitude.as_float(),
+                    1e-6
+                ),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                roundtrip.longitude.as_float(),
+                test.input.longitude.as_float()
+            );
+        });
+    }
+
+    #[test]
+    fn equal_coordinates_must_hash_and_compare_equal() {
+        let a = PackedCoordinates::from(
+            geographic::Coordinates::default().with_latitude(Latitude::from(0.1)),
+        );
+        let b = PackedCoordinates::from(
+            geographic::Coordinates::default().with_latitude(Latitude::from(0.1)),
+        );
+
+        assert_eq!(a, b);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(a);
+
+        assert!(set.contains(&b));
+    }
+}